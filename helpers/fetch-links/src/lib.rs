@@ -3,8 +3,13 @@ use napi::bindgen_prelude::*;
 #[cfg(feature = "napi-export")]
 use napi_derive::napi;
 
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, OnceLock, RwLock};
+
 use reqwest::Client;
-use scraper::{Html, Selector};
+use scraper::{Element, ElementRef, Html, Selector};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 use url::Url;
 
 #[cfg(feature = "napi-export")]
@@ -21,30 +26,205 @@ pub struct LinkInfo {
     pub text: String,
 }
 
-pub async fn fetch_links_internal(url: String) -> std::result::Result<Vec<LinkInfo>, String> {
+#[cfg(feature = "napi-export")]
+#[napi(object)]
+#[derive(Clone)]
+pub struct LinkHeaderEntry {
+    pub url: String,
+    pub rel: Option<String>,
+    pub params: HashMap<String, String>,
+}
+
+#[cfg(not(feature = "napi-export"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkHeaderEntry {
+    pub url: String,
+    pub rel: Option<String>,
+    pub params: HashMap<String, String>,
+}
+
+#[cfg(feature = "napi-export")]
+#[napi(object)]
+pub struct FetchedLinks {
+    pub links: Vec<LinkInfo>,
+    pub link_header: Vec<LinkHeaderEntry>,
+}
+
+#[cfg(not(feature = "napi-export"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedLinks {
+    pub links: Vec<LinkInfo>,
+    pub link_header: Vec<LinkHeaderEntry>,
+}
+
+/// A cached fetch result keyed by URL, carrying the validators needed to
+/// make a conditional request (`ETag` and/or `Last-Modified`) next time.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub body: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// Pluggable cache for conditional (`If-None-Match` / `If-Modified-Since`)
+/// re-fetches. Implement this to back `fetch_links_internal`'s optional
+/// cache handle with an in-memory map, a disk-backed store, or anything
+/// else; [`InMemoryFetchCache`] is provided for the common case.
+pub trait FetchCache: Send + Sync {
+    fn get(&self, url: &str) -> Option<CachedResponse>;
+    fn put(&self, url: &str, response: CachedResponse);
+}
+
+#[derive(Default)]
+pub struct InMemoryFetchCache {
+    entries: RwLock<HashMap<String, CachedResponse>>,
+}
+
+impl FetchCache for InMemoryFetchCache {
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        self.entries.read().unwrap().get(url).cloned()
+    }
+
+    fn put(&self, url: &str, response: CachedResponse) {
+        self.entries.write().unwrap().insert(url.to_string(), response);
+    }
+}
+
+pub async fn fetch_links_internal(
+    url: String,
+    cache: Option<&dyn FetchCache>,
+) -> std::result::Result<FetchedLinks, String> {
     let client = Client::builder()
         .user_agent("Forest/1.0 (Link Extractor)")
         .build()
         .unwrap_or_default();
     let base_url = Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
 
-    match client.get(&url).send().await {
-        Ok(response) => match response.text().await {
-            Ok(html) => {
-                let links = extract_links(&html, &base_url);
-                Ok(links)
+    let cached = cache.and_then(|c| c.get(&url));
+
+    let mut request = client.get(&url);
+    if let Some(cached) = &cached {
+        if let Some(etag) = &cached.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+        } else if let Some(last_modified) = &cached.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified.as_str());
+        }
+    }
+
+    match request.send().await {
+        Ok(response) => {
+            let link_header = response
+                .headers()
+                .get(reqwest::header::LINK)
+                .and_then(|value| value.to_str().ok())
+                .map(|raw| parse_link_header(raw, &base_url))
+                .unwrap_or_default();
+
+            if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                let cached = cached.ok_or_else(|| {
+                    "Received 304 Not Modified with no cached body".to_string()
+                })?;
+                let links = extract_links(&cached.body, &base_url);
+                return Ok(FetchedLinks { links, link_header });
             }
-            Err(e) => Err(format!("Failed to read response body: {}", e)),
-        },
+
+            let etag = response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from);
+            let last_modified = response
+                .headers()
+                .get(reqwest::header::LAST_MODIFIED)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from);
+
+            match response.text().await {
+                Ok(html) => {
+                    let links = extract_links(&html, &base_url);
+                    if let Some(c) = cache {
+                        if etag.is_some() || last_modified.is_some() {
+                            c.put(
+                                &url,
+                                CachedResponse {
+                                    body: html,
+                                    etag,
+                                    last_modified,
+                                },
+                            );
+                        }
+                    }
+                    Ok(FetchedLinks { links, link_header })
+                }
+                Err(e) => Err(format!("Failed to read response body: {}", e)),
+            }
+        }
         Err(e) => Err(format!("Failed to fetch URL: {}", e)),
     }
 }
 
+/// Parses an RFC 5988 `Link` header value into structured entries, resolving
+/// each URI against `base_url`. A missing `rel` parameter does not drop the
+/// entry; it is simply `None`.
+fn parse_link_header(raw: &str, base_url: &Url) -> Vec<LinkHeaderEntry> {
+    split_outside_quotes(raw, ',')
+        .iter()
+        .filter_map(|segment| parse_link_header_segment(segment.trim(), base_url))
+        .collect()
+}
+
+fn parse_link_header_segment(segment: &str, base_url: &Url) -> Option<LinkHeaderEntry> {
+    let uri_start = segment.find('<')?;
+    let uri_end = segment[uri_start..].find('>')? + uri_start;
+    let uri = segment[uri_start + 1..uri_end].trim();
+    let url = Url::parse(uri).ok().or_else(|| base_url.join(uri).ok())?.to_string();
+
+    let mut params = HashMap::new();
+    let mut rel = None;
+    for param in segment[uri_end + 1..].split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        // Bare extension flags without `=` (e.g. a lone `noopener`) are valid
+        // per RFC 5988 but carry no key/value pair; skip them rather than
+        // dropping the whole entry.
+        let Some((key, value)) = param.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_string();
+        let value = value.trim().trim_matches('"').to_string();
+        if key == "rel" {
+            rel = Some(value.clone());
+        }
+        params.insert(key, value);
+    }
+
+    Some(LinkHeaderEntry { url, rel, params })
+}
+
+/// Splits `raw` on top-level occurrences of `delimiter`, ignoring any that
+/// fall inside a double-quoted span (e.g. a `title="foo, bar"` parameter).
+fn split_outside_quotes(raw: &str, delimiter: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    for (i, c) in raw.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => {
+                parts.push(&raw[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&raw[start..]);
+    parts
+}
+
 #[cfg(feature = "napi-export")]
 #[napi]
 pub async fn fetch_links_async(url: String) -> Result<Vec<LinkInfo>> {
-    fetch_links_internal(url)
+    fetch_links_internal(url, None)
         .await
+        .map(|fetched| fetched.links)
         .map_err(Error::from_reason)
 }
 
@@ -54,6 +234,300 @@ pub async fn fetch_links(url: String) -> Result<Vec<LinkInfo>> {
     fetch_links_async(url).await
 }
 
+#[cfg(feature = "napi-export")]
+#[napi]
+pub async fn fetch_links_with_headers_async(url: String) -> Result<FetchedLinks> {
+    fetch_links_internal(url, None)
+        .await
+        .map_err(Error::from_reason)
+}
+
+#[cfg(feature = "napi-export")]
+#[napi(object)]
+pub struct RedirectedLinks {
+    pub final_url: String,
+    pub links: Vec<LinkInfo>,
+}
+
+#[cfg(not(feature = "napi-export"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectedLinks {
+    pub final_url: String,
+    pub links: Vec<LinkInfo>,
+}
+
+#[cfg(feature = "napi-export")]
+const MAX_REDIRECTS_DEFAULT: u32 = 5;
+
+/// Like [`fetch_links_internal`], but follows redirects manually so relative
+/// links are resolved against the final URL rather than the requested one.
+///
+/// Returns the final resolved URL alongside the extracted links. Errors out
+/// with a "too many redirects" message if `max_redirects` is exceeded.
+pub async fn fetch_links_with_redirects(
+    url: String,
+    max_redirects: u32,
+) -> std::result::Result<(String, Vec<LinkInfo>), String> {
+    let client = Client::builder()
+        .user_agent("Forest/1.0 (Link Extractor)")
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap_or_default();
+
+    let mut current_url = Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+
+    for _ in 0..=max_redirects {
+        let response = client
+            .get(current_url.clone())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch URL: {}", e))?;
+
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| "Redirect response missing Location header".to_string())?
+                .to_string();
+
+            current_url = match Url::parse(&location) {
+                Ok(absolute) => absolute,
+                Err(url::ParseError::RelativeUrlWithoutBase) => current_url
+                    .join(&location)
+                    .map_err(|e| format!("Failed to resolve redirect target: {}", e))?,
+                Err(e) => return Err(format!("Invalid redirect target: {}", e)),
+            };
+            continue;
+        }
+
+        let html = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read response body: {}", e))?;
+        let links = extract_links(&html, &current_url);
+        return Ok((current_url.to_string(), links));
+    }
+
+    Err("too many redirects".to_string())
+}
+
+#[cfg(feature = "napi-export")]
+#[napi]
+pub async fn fetch_links_with_redirects_async(
+    url: String,
+    max_redirects: Option<u32>,
+) -> Result<RedirectedLinks> {
+    let (final_url, links) =
+        fetch_links_with_redirects(url, max_redirects.unwrap_or(MAX_REDIRECTS_DEFAULT))
+            .await
+            .map_err(Error::from_reason)?;
+    Ok(RedirectedLinks { final_url, links })
+}
+
+#[cfg(feature = "napi-export")]
+#[napi(object)]
+#[derive(Clone)]
+pub struct LinkCheckResult {
+    pub url: String,
+    pub reachable: bool,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+#[cfg(not(feature = "napi-export"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkCheckResult {
+    pub url: String,
+    pub reachable: bool,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+type LinkCheckCache = Arc<RwLock<HashMap<String, LinkCheckResult>>>;
+
+fn link_check_cache() -> &'static LinkCheckCache {
+    static CACHE: OnceLock<LinkCheckCache> = OnceLock::new();
+    CACHE.get_or_init(|| Arc::new(RwLock::new(HashMap::new())))
+}
+
+/// Checks whether each of `links` is reachable, caching results by URL across
+/// calls so repeated checks of the same URL (common when crawling) are free.
+///
+/// Entries whose URL starts with any of `skip_prefixes` are left out of the
+/// result entirely (e.g. `mailto:`, `#` anchors, known-slow hosts).
+pub async fn check_links(
+    links: Vec<LinkInfo>,
+    skip_prefixes: Vec<String>,
+) -> Vec<LinkCheckResult> {
+    let cache = link_check_cache();
+    let client = Client::builder()
+        .user_agent("Forest/1.0 (Link Extractor)")
+        .build()
+        .unwrap_or_default();
+
+    let mut results = Vec::with_capacity(links.len());
+    for link in links {
+        if skip_prefixes.iter().any(|prefix| link.url.starts_with(prefix.as_str())) {
+            continue;
+        }
+
+        if let Some(cached) = cache.read().unwrap().get(&link.url) {
+            results.push(cached.clone());
+            continue;
+        }
+
+        let result = check_single_link(&client, &link.url).await;
+        cache.write().unwrap().insert(link.url.clone(), result.clone());
+        results.push(result);
+    }
+    results
+}
+
+async fn check_single_link(client: &Client, url: &str) -> LinkCheckResult {
+    let head_result = client
+        .head(url)
+        .header(reqwest::header::ACCEPT, "text/html, */*")
+        .send()
+        .await;
+
+    let response = match head_result {
+        Ok(response) if response.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED => {
+            client
+                .get(url)
+                .header(reqwest::header::ACCEPT, "text/html, */*")
+                .send()
+                .await
+        }
+        other => other,
+    };
+
+    match response {
+        Ok(response) => {
+            let status = response.status();
+            let reachable = status.is_success() || status == reqwest::StatusCode::NOT_MODIFIED;
+            LinkCheckResult {
+                url: url.to_string(),
+                reachable,
+                status: Some(status.as_u16()),
+                error: None,
+            }
+        }
+        Err(e) => LinkCheckResult {
+            url: url.to_string(),
+            reachable: false,
+            status: None,
+            error: Some(format!("Failed to check URL: {}", e)),
+        },
+    }
+}
+
+#[cfg(feature = "napi-export")]
+#[napi]
+pub async fn check_links_async(
+    links: Vec<LinkInfo>,
+    skip_prefixes: Vec<String>,
+) -> Result<Vec<LinkCheckResult>> {
+    Ok(check_links(links, skip_prefixes).await)
+}
+
+#[cfg(feature = "napi-export")]
+#[napi]
+pub async fn crawl_site_async(
+    start_url: String,
+    max_depth: u32,
+    max_concurrency: u32,
+) -> Result<HashMap<String, Vec<LinkInfo>>> {
+    crawl_site(start_url, max_depth, max_concurrency as usize, None)
+        .await
+        .map_err(Error::from_reason)
+}
+
+/// Breadth-first, same-origin crawl starting from `start_url`.
+///
+/// Fetches are bounded by `max_concurrency` via a `tokio::Semaphore`, and the
+/// crawl stops once the work queue is empty and no fetches remain in flight.
+/// Fetch errors are non-fatal: the offending URL is simply skipped. When
+/// `cache` is set, it is shared across every fetch in the crawl so pages
+/// revisited across runs can be validated with a conditional request
+/// instead of re-fetched in full. Within one crawl each URL is fetched at
+/// most once, however many pages link to it.
+pub async fn crawl_site(
+    start_url: String,
+    max_depth: u32,
+    max_concurrency: usize,
+    cache: Option<Arc<dyn FetchCache>>,
+) -> std::result::Result<HashMap<String, Vec<LinkInfo>>, String> {
+    let origin_host = Url::parse(&start_url)
+        .map_err(|e| format!("Invalid URL: {}", e))?
+        .host_str()
+        .ok_or_else(|| "Start URL has no host".to_string())?
+        .to_string();
+
+    let semaphore = Arc::new(Semaphore::new(max_concurrency.max(1)));
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut queue: VecDeque<(String, u32)> = VecDeque::new();
+    let mut visited: HashMap<String, Vec<LinkInfo>> = HashMap::new();
+
+    seen.insert(normalize_crawl_url(&start_url));
+    queue.push_back((start_url, 0));
+
+    let mut in_flight: JoinSet<(String, u32, std::result::Result<Vec<LinkInfo>, String>)> =
+        JoinSet::new();
+
+    loop {
+        while let Some((url, depth)) = queue.pop_front() {
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let fetch_url = url.clone();
+            let fetch_cache = cache.clone();
+            in_flight.spawn(async move {
+                let result = fetch_links_internal(fetch_url, fetch_cache.as_deref())
+                    .await
+                    .map(|fetched| fetched.links);
+                drop(permit);
+                (url, depth, result)
+            });
+        }
+
+        let Some(joined) = in_flight.join_next().await else {
+            break;
+        };
+        let Ok((url, depth, result)) = joined else {
+            continue;
+        };
+
+        if let Ok(links) = result {
+            if depth < max_depth {
+                for link in &links {
+                    let normalized = normalize_crawl_url(&link.url);
+                    if is_same_origin(&normalized, &origin_host) && !seen.contains(&normalized) {
+                        seen.insert(normalized.clone());
+                        queue.push_back((normalized, depth + 1));
+                    }
+                }
+            }
+            visited.insert(url, links);
+        }
+    }
+
+    Ok(visited)
+}
+
+fn normalize_crawl_url(url: &str) -> String {
+    url.trim_end_matches('/').to_string()
+}
+
+fn is_same_origin(url: &str, origin_host: &str) -> bool {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|host| host == origin_host))
+        .unwrap_or(false)
+}
+
 fn extract_links(html: &str, base_url: &Url) -> Vec<LinkInfo> {
     let document = Html::parse_document(html);
     let selector = Selector::parse("a[href]").unwrap();
@@ -76,6 +550,105 @@ fn normalize_link_text(text: &str) -> String {
     text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
+/// Like [`fetch_links_internal`], but only collects links found inside the
+/// page's primary content region, skipping navigation/footer/sidebar chrome.
+pub async fn fetch_content_links_internal(
+    url: String,
+) -> std::result::Result<Vec<LinkInfo>, String> {
+    let client = Client::builder()
+        .user_agent("Forest/1.0 (Link Extractor)")
+        .build()
+        .unwrap_or_default();
+    let base_url = Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+
+    match client.get(&url).send().await {
+        Ok(response) => match response.text().await {
+            Ok(html) => Ok(extract_content_links(&html, &base_url)),
+            Err(e) => Err(format!("Failed to read response body: {}", e)),
+        },
+        Err(e) => Err(format!("Failed to fetch URL: {}", e)),
+    }
+}
+
+#[cfg(feature = "napi-export")]
+#[napi]
+pub async fn fetch_content_links_async(url: String) -> Result<Vec<LinkInfo>> {
+    fetch_content_links_internal(url)
+        .await
+        .map_err(Error::from_reason)
+}
+
+const CONTENT_CANDIDATE_TAGS: [&str; 4] = ["p", "article", "div", "section"];
+const BOILERPLATE_HINTS: [&str; 6] = ["nav", "footer", "sidebar", "comment", "menu", "ad"];
+
+/// Readability-style link extraction: scores block elements by text length
+/// and comma count, propagates that score to ancestors with decreasing
+/// weight (penalizing nodes whose class/id look like chrome), then collects
+/// `a[href]` elements only from within the highest-scoring container.
+/// Falls back to the whole document if no candidate scores above zero.
+fn extract_content_links(html: &str, base_url: &Url) -> Vec<LinkInfo> {
+    let document = Html::parse_document(html);
+    let link_selector = Selector::parse("a[href]").unwrap();
+    let content_root = find_content_root(&document);
+
+    let links = |element: ElementRef<'_>| {
+        let href = element.value().attr("href")?;
+        let url = Url::parse(href).ok().or_else(|| base_url.join(href).ok())?;
+        let text = normalize_link_text(element.text().collect::<String>().as_str());
+        Some(LinkInfo {
+            url: url.to_string(),
+            text,
+        })
+    };
+
+    match content_root {
+        Some(root) => root.select(&link_selector).filter_map(links).collect(),
+        None => document.select(&link_selector).filter_map(links).collect(),
+    }
+}
+
+fn find_content_root(document: &Html) -> Option<ElementRef<'_>> {
+    let mut scores: HashMap<ego_tree::NodeId, f64> = HashMap::new();
+
+    for tag in CONTENT_CANDIDATE_TAGS {
+        let selector = Selector::parse(tag).unwrap();
+        for element in document.select(&selector) {
+            let text: String = element.text().collect();
+            let text_len = text.trim().len();
+            if text_len < 25 {
+                continue;
+            }
+            let comma_count = text.matches(',').count();
+            let base_score = 1.0 + comma_count as f64 + (text_len as f64 / 100.0).min(3.0);
+
+            let mut weight = base_score;
+            let mut current = Some(element);
+            while let Some(node) = current {
+                let penalty = if is_boilerplate(&node) { 0.2 } else { 1.0 };
+                *scores.entry(node.id()).or_insert(0.0) += weight * penalty;
+                current = node.parent_element();
+                weight *= 0.5;
+            }
+        }
+    }
+
+    scores
+        .into_iter()
+        .filter(|(_, score)| *score > 0.0)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .and_then(|(id, _)| document.tree.get(id))
+        .and_then(ElementRef::wrap)
+}
+
+fn is_boilerplate(element: &ElementRef<'_>) -> bool {
+    let value = element.value();
+    let class = value.attr("class").unwrap_or("").to_lowercase();
+    let id = value.attr("id").unwrap_or("").to_lowercase();
+    BOILERPLATE_HINTS
+        .iter()
+        .any(|hint| class.contains(hint) || id.contains(hint))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +677,92 @@ mod tests {
         assert_eq!(links[1].url, "https://google.com/");
         assert_eq!(links[1].text, "Link 2");
     }
+
+    #[test]
+    fn test_normalize_crawl_url_trims_trailing_slash() {
+        assert_eq!(
+            normalize_crawl_url("https://example.com/page/"),
+            "https://example.com/page"
+        );
+        assert_eq!(
+            normalize_crawl_url("https://example.com/page"),
+            "https://example.com/page"
+        );
+    }
+
+    #[test]
+    fn test_is_same_origin() {
+        assert!(is_same_origin("https://example.com/page", "example.com"));
+        assert!(!is_same_origin("https://other.com/page", "example.com"));
+        assert!(!is_same_origin("not a url", "example.com"));
+    }
+
+    #[test]
+    fn test_parse_link_header_basic() {
+        let base_url = Url::parse("https://api.example.com/page/1").unwrap();
+        let raw = r#"<https://api.example.com/page/2>; rel="next", <https://api.example.com/page/9>; rel="last""#;
+        let entries = parse_link_header(raw, &base_url);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].url, "https://api.example.com/page/2");
+        assert_eq!(entries[0].rel.as_deref(), Some("next"));
+        assert_eq!(entries[1].url, "https://api.example.com/page/9");
+        assert_eq!(entries[1].rel.as_deref(), Some("last"));
+    }
+
+    #[test]
+    fn test_parse_link_header_relative_uri_and_unquoted_value() {
+        let base_url = Url::parse("https://api.example.com/page/1").unwrap();
+        let raw = r#"</page/2>; rel=next"#;
+        let entries = parse_link_header(raw, &base_url);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].url, "https://api.example.com/page/2");
+        assert_eq!(entries[0].rel.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_parse_link_header_missing_rel_is_kept() {
+        let base_url = Url::parse("https://api.example.com/page/1").unwrap();
+        let raw = r#"<https://api.example.com/alternate>; type="text/html""#;
+        let entries = parse_link_header(raw, &base_url);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].rel, None);
+        assert_eq!(entries[0].params.get("type").map(String::as_str), Some("text/html"));
+    }
+
+    #[test]
+    fn test_parse_link_header_keeps_entry_with_bare_flag_param() {
+        let base_url = Url::parse("https://api.example.com/page/1").unwrap();
+        let raw = r#"<https://api.example.com/page/2>; rel="next"; noopener"#;
+        let entries = parse_link_header(raw, &base_url);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].url, "https://api.example.com/page/2");
+        assert_eq!(entries[0].rel.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_split_outside_quotes_ignores_commas_in_quotes() {
+        let raw = r#"a, b="x, y", c"#;
+        let parts = split_outside_quotes(raw, ',');
+        assert_eq!(parts, vec!["a", r#" b="x, y""#, " c"]);
+    }
+
+    #[test]
+    fn test_extract_content_links_skips_nav_and_footer() {
+        let html = r#"
+            <html>
+              <body>
+                <nav class="site-nav"><a href="/home">Home</a><a href="/about">About</a></nav>
+                <article>
+                  <p>This is the real article body, with enough prose and, commas, to score well above the surrounding chrome elements on the page.</p>
+                  <a href="/related-post">Related post</a>
+                </article>
+                <footer id="site-footer"><a href="/privacy">Privacy</a></footer>
+              </body>
+            </html>
+        "#;
+        let base_url = Url::parse("https://example.com").unwrap();
+        let links = extract_content_links(html, &base_url);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].url, "https://example.com/related-post");
+    }
 }
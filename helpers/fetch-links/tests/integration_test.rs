@@ -1,5 +1,11 @@
-use fetch_links::fetch_links_internal;
+use std::sync::Arc;
+
+use fetch_links::{
+    check_links, crawl_site, fetch_content_links_internal, fetch_links_internal,
+    fetch_links_with_redirects, CachedResponse, FetchCache, InMemoryFetchCache, LinkInfo,
+};
 use httpmock::prelude::*;
+use httpmock::Method::HEAD;
 
 #[tokio::test]
 async fn test_fetch_links_from_url() {
@@ -19,13 +25,228 @@ async fn test_fetch_links_from_url() {
   });
 
   let url = format!("http://127.0.0.1:{}/test", server.port());
-  let links = fetch_links_internal(url)
+  let fetched = fetch_links_internal(url, None)
+    .await
+    .expect("fetch_links_internal should succeed");
+
+  assert_eq!(fetched.links.len(), 2);
+  assert_eq!(fetched.links[0].url, format!("http://127.0.0.1:{}/relative", server.port()));
+  assert_eq!(fetched.links[0].text, "Relative Link");
+  assert_eq!(fetched.links[1].url, "https://example.org/absolute");
+  assert_eq!(fetched.links[1].text, "Absolute Link");
+  assert!(fetched.link_header.is_empty());
+}
+
+#[tokio::test]
+async fn test_fetch_links_internal_parses_link_header() {
+  let server = MockServer::start();
+  let base = format!("http://127.0.0.1:{}", server.port());
+
+  server.mock(|when, then| {
+    when.method(GET).path("/page");
+    then
+      .status(200)
+      .header("content-type", "text/html")
+      .header(
+        "link",
+        r#"</page/2>; rel="next"; title="Page 2", <https://example.org/last>; rel="last""#,
+      )
+      .body("");
+  });
+
+  let fetched = fetch_links_internal(format!("{base}/page"), None)
     .await
     .expect("fetch_links_internal should succeed");
 
-  assert_eq!(links.len(), 2);
-  assert_eq!(links[0].url, format!("http://127.0.0.1:{}/relative", server.port()));
-  assert_eq!(links[0].text, "Relative Link");
-  assert_eq!(links[1].url, "https://example.org/absolute");
-  assert_eq!(links[1].text, "Absolute Link");
+  assert_eq!(fetched.link_header.len(), 2);
+  assert_eq!(fetched.link_header[0].url, format!("{base}/page/2"));
+  assert_eq!(fetched.link_header[0].rel.as_deref(), Some("next"));
+  assert_eq!(fetched.link_header[0].params.get("title").map(String::as_str), Some("Page 2"));
+  assert_eq!(fetched.link_header[1].url, "https://example.org/last");
+  assert_eq!(fetched.link_header[1].rel.as_deref(), Some("last"));
+}
+
+#[tokio::test]
+async fn test_crawl_site_follows_same_origin_links() {
+  let server = MockServer::start();
+  let base = format!("http://127.0.0.1:{}", server.port());
+
+  server.mock(|when, then| {
+    when.method(GET).path("/");
+    then.status(200).header("content-type", "text/html").body(format!(
+      r#"<a href="{base}/page-a">Page A</a><a href="https://external.example.org/">External</a>"#
+    ));
+  });
+  server.mock(|when, then| {
+    when.method(GET).path("/page-a");
+    then.status(200).header("content-type", "text/html").body("<a href=\"/\">Home</a>");
+  });
+
+  let visited = crawl_site(format!("{base}/"), 2, 4, None)
+    .await
+    .expect("crawl_site should succeed");
+
+  assert_eq!(visited.len(), 2);
+  assert!(visited.contains_key(&format!("{base}/")));
+  assert!(visited.contains_key(&format!("{base}/page-a")));
+}
+
+#[tokio::test]
+async fn test_fetch_links_with_redirects_follows_to_final_url() {
+  let server = MockServer::start();
+  let base = format!("http://127.0.0.1:{}", server.port());
+
+  server.mock(|when, then| {
+    when.method(GET).path("/start");
+    then.status(302).header("location", "/final");
+  });
+  server.mock(|when, then| {
+    when.method(GET).path("/final");
+    then.status(200).header("content-type", "text/html").body(r#"<a href="/here">Here</a>"#);
+  });
+
+  let (final_url, links) = fetch_links_with_redirects(format!("{base}/start"), 5)
+    .await
+    .expect("fetch_links_with_redirects should succeed");
+
+  assert_eq!(final_url, format!("{base}/final"));
+  assert_eq!(links.len(), 1);
+  assert_eq!(links[0].url, format!("{base}/here"));
+}
+
+#[tokio::test]
+async fn test_fetch_links_with_redirects_too_many_redirects() {
+  let server = MockServer::start();
+  let base = format!("http://127.0.0.1:{}", server.port());
+
+  server.mock(|when, then| {
+    when.method(GET).path("/loop");
+    then.status(302).header("location", "/loop");
+  });
+
+  let result = fetch_links_with_redirects(format!("{base}/loop"), 2).await;
+
+  assert!(result.is_err());
+  assert!(result.unwrap_err().contains("too many redirects"));
+}
+
+#[tokio::test]
+async fn test_check_links_reports_reachability_and_skips_prefixes() {
+  let server = MockServer::start();
+  let base = format!("http://127.0.0.1:{}", server.port());
+
+  server.mock(|when, then| {
+    when.method(HEAD).path("/ok");
+    then.status(200);
+  });
+  server.mock(|when, then| {
+    when.method(HEAD).path("/missing");
+    then.status(404);
+  });
+
+  let links = vec![
+    LinkInfo { url: format!("{base}/ok"), text: "Ok".to_string() },
+    LinkInfo { url: format!("{base}/missing"), text: "Missing".to_string() },
+    LinkInfo { url: "mailto:someone@example.com".to_string(), text: "Mail".to_string() },
+  ];
+
+  let results = check_links(links, vec!["mailto:".to_string()]).await;
+
+  assert_eq!(results.len(), 2);
+  assert!(results[0].reachable);
+  assert_eq!(results[0].status, Some(200));
+  assert!(!results[1].reachable);
+  assert_eq!(results[1].status, Some(404));
+}
+
+#[tokio::test]
+async fn test_fetch_content_links_internal_skips_boilerplate() {
+  let html = r#"
+    <html>
+      <body>
+        <nav class="main-nav"><a href="/home">Home</a></nav>
+        <article>
+          <p>A long-form piece of writing with plenty of prose, several commas, and a real link to follow.</p>
+          <a href="/deep-dive">Deep dive</a>
+        </article>
+        <div class="sidebar"><a href="/ads">Sponsored</a></div>
+      </body>
+    </html>
+    "#;
+
+  let server = MockServer::start();
+  server.mock(|when, then| {
+    when.method(GET).path("/article");
+    then.status(200).header("content-type", "text/html").body(html);
+  });
+
+  let url = format!("http://127.0.0.1:{}/article", server.port());
+  let links = fetch_content_links_internal(url)
+    .await
+    .expect("fetch_content_links_internal should succeed");
+
+  assert_eq!(links.len(), 1);
+  assert_eq!(links[0].url, format!("http://127.0.0.1:{}/deep-dive", server.port()));
+}
+
+#[tokio::test]
+async fn test_fetch_links_internal_reuses_cached_body_on_304() {
+  let server = MockServer::start();
+
+  let conditional_hit = server.mock(|when, then| {
+    when.method(GET).path("/cached").header("if-none-match", "\"v1\"");
+    then.status(304);
+  });
+
+  let url = format!("http://127.0.0.1:{}/cached", server.port());
+  let cache = InMemoryFetchCache::default();
+  cache.put(
+    &url,
+    CachedResponse {
+      body: r#"<a href="/first">First</a>"#.to_string(),
+      etag: Some("\"v1\"".to_string()),
+      last_modified: None,
+    },
+  );
+
+  let fetched = fetch_links_internal(url.clone(), Some(&cache))
+    .await
+    .expect("conditional fetch should succeed");
+
+  assert_eq!(fetched.links.len(), 1);
+  assert_eq!(fetched.links[0].url, format!("http://127.0.0.1:{}/first", server.port()));
+  conditional_hit.assert_hits(1);
+}
+
+#[tokio::test]
+async fn test_crawl_site_sends_conditional_request_from_shared_cache() {
+  let server = MockServer::start();
+  let base = format!("http://127.0.0.1:{}", server.port());
+
+  let conditional_hit = server.mock(|when, then| {
+    when.method(GET).path("/").header("if-none-match", "\"v1\"");
+    then.status(304);
+  });
+  let page_a = server.mock(|when, then| {
+    when.method(GET).path("/page-a");
+    then.status(200).header("content-type", "text/html").body("");
+  });
+
+  let cache: Arc<dyn FetchCache> = Arc::new(InMemoryFetchCache::default());
+  cache.put(
+    &format!("{base}/"),
+    CachedResponse {
+      body: format!(r#"<a href="{base}/page-a">Page A</a>"#),
+      etag: Some("\"v1\"".to_string()),
+      last_modified: None,
+    },
+  );
+
+  let visited = crawl_site(format!("{base}/"), 1, 2, Some(cache))
+    .await
+    .expect("crawl_site should succeed");
+
+  assert_eq!(visited.len(), 2);
+  conditional_hit.assert_hits(1);
+  page_a.assert_hits(1);
 }